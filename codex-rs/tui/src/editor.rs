@@ -1,9 +1,17 @@
 use std::env;
 use std::fs;
+use std::io::stdout;
 use std::process::Stdio;
 
 use color_eyre::eyre::Report;
 use color_eyre::eyre::Result;
+use crossterm::execute;
+use crossterm::terminal::disable_raw_mode;
+use crossterm::terminal::enable_raw_mode;
+use crossterm::terminal::EnterAlternateScreen;
+use crossterm::terminal::LeaveAlternateScreen;
+use ratatui::backend::Backend;
+use ratatui::Terminal;
 use shlex::split as shlex_split;
 use tempfile::Builder;
 use thiserror::Error;
@@ -17,14 +25,31 @@ pub(crate) enum EditorError {
     ParseFailed,
     #[error("editor command is empty")]
     EmptyCommand,
+    #[error("editor buffer was empty or contained only comments")]
+    Aborted,
 }
 
+/// Platform default editor used when neither `VISUAL` nor `EDITOR` is set.
+#[cfg(unix)]
+const DEFAULT_EDITOR: &str = "vi";
+#[cfg(windows)]
+const DEFAULT_EDITOR: &str = "notepad.exe";
+
 /// Resolve the editor command from environment variables.
 /// Prefers `VISUAL` over `EDITOR`.
-pub(crate) fn resolve_editor_command() -> std::result::Result<Vec<String>, EditorError> {
-    let raw = env::var("VISUAL")
-        .or_else(|_| env::var("EDITOR"))
-        .map_err(|_| EditorError::MissingEditor)?;
+///
+/// If neither is set, falls back to [`DEFAULT_EDITOR`] unless
+/// `require_explicit` is `true`, in which case [`EditorError::MissingEditor`]
+/// is returned instead, for callers that need the user to have configured an
+/// editor explicitly.
+pub(crate) fn resolve_editor_command(
+    require_explicit: bool,
+) -> std::result::Result<Vec<String>, EditorError> {
+    let raw = match env::var("VISUAL").or_else(|_| env::var("EDITOR")) {
+        Ok(raw) => raw,
+        Err(_) if require_explicit => return Err(EditorError::MissingEditor),
+        Err(_) => DEFAULT_EDITOR.to_string(),
+    };
     let parts = shlex_split(&raw).ok_or(EditorError::ParseFailed)?;
     if parts.is_empty() {
         return Err(EditorError::EmptyCommand);
@@ -32,21 +57,180 @@ pub(crate) fn resolve_editor_command() -> std::result::Result<Vec<String>, Edito
     Ok(parts)
 }
 
-/// Write `seed` to a temp file, launch the editor command, and return the updated content.
-pub(crate) async fn run_editor(seed: &str, editor_cmd: &[String]) -> Result<String> {
+/// Basename of an editor command's argv[0], used to pick editor-specific
+/// behavior (hardening flags, cursor-position syntax) without caring about
+/// the full path the user configured.
+fn editor_basename(editor_cmd: &[String]) -> &str {
+    editor_cmd
+        .first()
+        .map(String::as_str)
+        .and_then(|raw| std::path::Path::new(raw).file_name())
+        .and_then(|name| name.to_str())
+        .unwrap_or("")
+}
+
+/// Extra arguments that stop a known editor from leaving residual copies of
+/// the buffer on disk (swap files, undo history, viminfo, backups). Unknown
+/// editors are left untouched since we have no safe way to harden them.
+fn hardening_args(editor_basename: &str) -> Vec<String> {
+    match editor_basename {
+        "vim" | "nvim" => vec![
+            "-n".to_string(),
+            "-i".to_string(),
+            "NONE".to_string(),
+            "-c".to_string(),
+            "set nobackup noundofile".to_string(),
+        ],
+        "emacs" => vec![
+            "--no-init-file".to_string(),
+            "--eval".to_string(),
+            "(setq make-backup-files nil auto-save-default nil)".to_string(),
+        ],
+        _ => Vec::new(),
+    }
+}
+
+/// A 1-indexed cursor position to open the editor at.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CursorPosition {
+    pub(crate) line: usize,
+    pub(crate) column: Option<usize>,
+}
+
+/// Editor-specific argument(s) that place the cursor at `pos` when opening
+/// `file_path`. Returns the arguments to insert before the file path, and the
+/// file path argument itself — some editors want the position folded into
+/// the path (`file:line:col`) rather than passed as a separate flag.
+///
+/// Recognized editors: `vi`/`vim`/`nvim`/`nano`/`emacs` (`+LINE`);
+/// `code`/`code-insiders`/`codium` (`--goto file:line[:col]`, per `code
+/// --help`); `subl`/`sublime_text`/`hx`/`helix` (bare `file:line[:col]`
+/// positional argument, no flag). Unknown editors get no position argument
+/// at all.
+fn cursor_position_args(
+    editor_basename: &str,
+    pos: CursorPosition,
+    file_path: &str,
+) -> (Vec<String>, String) {
+    let location = match pos.column {
+        Some(col) => format!("{file_path}:{}:{col}", pos.line),
+        None => format!("{file_path}:{}", pos.line),
+    };
+    match editor_basename {
+        "vi" | "vim" | "nvim" | "nano" | "emacs" => {
+            (vec![format!("+{}", pos.line)], file_path.to_string())
+        }
+        "code" | "code-insiders" | "codium" => (vec!["--goto".to_string()], location),
+        "subl" | "sublime_text" | "hx" | "helix" => (Vec::new(), location),
+        _ => (Vec::new(), file_path.to_string()),
+    }
+}
+
+/// Marks the start of the appended help block so it can be cut back out by
+/// position rather than by re-filtering the whole buffer for
+/// `comment_marker` lines, which would also delete unrelated content the
+/// user wrote that happens to start with the same marker (e.g. a markdown
+/// heading in a `.md` buffer).
+const HELP_BLOCK_SENTINEL: &str = "--- codex: everything below this line is ignored ---";
+
+/// Render `help` as lines prefixed with `comment_marker`, for appending to an
+/// editor buffer as in-buffer instructions that never end up in the saved
+/// value. The block starts with [`HELP_BLOCK_SENTINEL`] so [`strip_help_block`]
+/// can find exactly where it begins.
+fn render_help_block(help: &str, comment_marker: char) -> String {
+    let mut block = format!("{comment_marker} {HELP_BLOCK_SENTINEL}\n");
+    for line in help.lines() {
+        block.push_str(&format!("{comment_marker} {line}\n"));
+    }
+    block
+}
+
+/// Cut everything from [`HELP_BLOCK_SENTINEL`] onward out of `contents`,
+/// along with any newlines directly before it. If the sentinel isn't found (e.g. the user deleted it along with the rest of
+/// the help block), `contents` is returned unchanged.
+fn strip_help_block(contents: &str, comment_marker: char) -> String {
+    let sentinel_line = format!("{comment_marker} {HELP_BLOCK_SENTINEL}");
+    match contents.find(&sentinel_line) {
+        Some(idx) => contents[..idx].trim_end_matches('\n').to_string(),
+        None => contents.to_string(),
+    }
+}
+
+/// In-buffer instructions appended to the editor buffer as a comment block
+/// and stripped back out before the content is returned. See [`run_editor`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct HelpBlock<'a> {
+    pub(crate) text: &'a str,
+    pub(crate) comment_marker: char,
+}
+
+/// Options controlling how [`run_editor`] (and [`run_editor_suspended`])
+/// prepare the buffer and invoke the editor. Grouped into a struct, rather
+/// than threaded through as same-typed positional parameters, so callers
+/// can't accidentally transpose `sensitive` with another flag or misplace
+/// `cursor`.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct EditorOptions<'a> {
+    /// The buffer may contain secrets or untrusted text, so known editors
+    /// are launched with flags that suppress swap files, undo history, and
+    /// backups (see [`hardening_args`]); unknown editors are launched as-is.
+    pub(crate) sensitive: bool,
+    /// See [`HelpBlock`]. `None` means the buffer is handed to the editor
+    /// exactly as seeded, with nothing appended or stripped afterward.
+    pub(crate) help: Option<HelpBlock<'a>>,
+    /// Where to place the cursor when the editor opens (see
+    /// [`cursor_position_args`]). `None` leaves it at the editor's default.
+    pub(crate) cursor: Option<CursorPosition>,
+}
+
+/// Write `seed` to a temp file, launch the editor command, and return the
+/// updated content.
+///
+/// When `options.help` is `Some`, it is appended to the buffer as a block of
+/// lines prefixed with its `comment_marker` (e.g. `#`), and only that
+/// appended block is stripped back out of whatever the user saved — content
+/// above it is returned as written (minus trailing newlines) even if it
+/// happens to contain `comment_marker`-prefixed lines of its own. If nothing
+/// remains once the
+/// block is removed, this is treated as the user cancelling and
+/// [`EditorError::Aborted`] is returned. Callers that leave `help` as `None`
+/// get the buffer back completely untouched.
+pub(crate) async fn run_editor(
+    seed: &str,
+    editor_cmd: &[String],
+    options: EditorOptions<'_>,
+) -> Result<String> {
     if editor_cmd.is_empty() {
         return Err(Report::msg("editor command is empty"));
     }
 
     let tempfile = Builder::new().suffix(".md").tempfile()?;
-    fs::write(tempfile.path(), seed)?;
+    let seeded = match options.help {
+        Some(help) => format!(
+            "{seed}\n{}",
+            render_help_block(help.text, help.comment_marker)
+        ),
+        None => seed.to_string(),
+    };
+    fs::write(tempfile.path(), seeded)?;
+
+    let basename = editor_basename(editor_cmd);
+    let tempfile_path = tempfile.path().to_string_lossy().to_string();
+    let (position_args, file_arg) = match options.cursor {
+        Some(pos) => cursor_position_args(basename, pos, &tempfile_path),
+        None => (Vec::new(), tempfile_path.clone()),
+    };
 
     let mut cmd = Command::new(&editor_cmd[0]);
     if editor_cmd.len() > 1 {
         cmd.args(&editor_cmd[1..]);
     }
+    if options.sensitive {
+        cmd.args(hardening_args(basename));
+    }
+    cmd.args(position_args);
     let status = cmd
-        .arg(tempfile.path())
+        .arg(file_arg)
         .stdin(Stdio::inherit())
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
@@ -58,9 +242,122 @@ pub(crate) async fn run_editor(seed: &str, editor_cmd: &[String]) -> Result<Stri
     }
 
     let contents = fs::read_to_string(tempfile.path())?;
+    let contents = match options.help {
+        Some(help) => strip_help_block(&contents, help.comment_marker),
+        None => contents,
+    };
+
+    if options.help.is_some() && contents.trim().is_empty() {
+        return Err(EditorError::Aborted.into());
+    }
+
     Ok(contents)
 }
 
+/// The terminal operations [`SuspendedTerminal`] needs, pulled out behind a
+/// trait so tests can swap in a fake that records calls instead of touching
+/// the real tty (raw mode and the alternate screen aren't meaningfully
+/// testable against a process with no controlling terminal).
+trait TerminalIo {
+    fn disable_raw_mode(&self) -> std::io::Result<()>;
+    fn enable_raw_mode(&self) -> std::io::Result<()>;
+    fn leave_alternate_screen(&self) -> std::io::Result<()>;
+    fn enter_alternate_screen(&self) -> std::io::Result<()>;
+}
+
+/// [`TerminalIo`] backed by the real crossterm calls.
+struct CrosstermIo;
+
+impl TerminalIo for CrosstermIo {
+    fn disable_raw_mode(&self) -> std::io::Result<()> {
+        disable_raw_mode()
+    }
+
+    fn enable_raw_mode(&self) -> std::io::Result<()> {
+        enable_raw_mode()
+    }
+
+    fn leave_alternate_screen(&self) -> std::io::Result<()> {
+        execute!(stdout(), LeaveAlternateScreen)
+    }
+
+    fn enter_alternate_screen(&self) -> std::io::Result<()> {
+        execute!(stdout(), EnterAlternateScreen)
+    }
+}
+
+/// Scope guard that leaves the alternate screen and disables raw mode for as
+/// long as it is alive, restoring both on drop. This is what lets a child
+/// process like an external editor draw directly to the terminal without the
+/// TUI's alternate-screen buffer fighting it, while guaranteeing the TUI
+/// comes back even if the guarded scope returns early, errors, or panics.
+struct SuspendedTerminal<T: TerminalIo = CrosstermIo> {
+    io: T,
+}
+
+impl SuspendedTerminal<CrosstermIo> {
+    fn enter() -> Result<Self> {
+        Self::enter_with(CrosstermIo)
+    }
+}
+
+impl<T: TerminalIo> SuspendedTerminal<T> {
+    fn enter_with(io: T) -> Result<Self> {
+        io.disable_raw_mode()?;
+        if let Err(err) = io.leave_alternate_screen() {
+            // We already left raw mode; undo that before propagating so we
+            // don't strand the terminal with raw mode off and no guard
+            // alive to restore it.
+            let _ = io.enable_raw_mode();
+            return Err(err.into());
+        }
+        Ok(Self { io })
+    }
+}
+
+impl<T: TerminalIo> Drop for SuspendedTerminal<T> {
+    fn drop(&mut self) {
+        // Best-effort: we're already unwinding or returning, so there's no
+        // good way to propagate a failure here.
+        let _ = self.io.enter_alternate_screen();
+        let _ = self.io.enable_raw_mode();
+    }
+}
+
+/// Like [`run_editor`], but suspends the TUI (leaves the alternate screen and
+/// disables raw mode) before spawning the editor, and restores the TUI with a
+/// full redraw afterwards — even if the editor errors or exits non-zero.
+pub(crate) async fn run_editor_suspended<B>(
+    terminal: &mut Terminal<B>,
+    seed: &str,
+    editor_cmd: &[String],
+    options: EditorOptions<'_>,
+) -> Result<String>
+where
+    B: Backend,
+{
+    run_editor_suspended_with(terminal, seed, editor_cmd, options, CrosstermIo).await
+}
+
+async fn run_editor_suspended_with<B, T>(
+    terminal: &mut Terminal<B>,
+    seed: &str,
+    editor_cmd: &[String],
+    options: EditorOptions<'_>,
+    io: T,
+) -> Result<String>
+where
+    B: Backend,
+    T: TerminalIo,
+{
+    let result = {
+        let _guard = SuspendedTerminal::enter_with(io)?;
+        run_editor(seed, editor_cmd, options).await
+    };
+    terminal.clear()?;
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -105,20 +402,32 @@ mod tests {
             env::set_var("VISUAL", "vis");
             env::set_var("EDITOR", "ed");
         }
-        let cmd = resolve_editor_command().unwrap();
+        let cmd = resolve_editor_command(false).unwrap();
         assert_eq!(cmd, vec!["vis".to_string()]);
     }
 
     #[test]
     #[serial]
-    fn resolve_editor_errors_when_unset() {
+    fn resolve_editor_falls_back_to_default_when_unset() {
+        let _guard = EnvGuard::new();
+        unsafe {
+            env::remove_var("VISUAL");
+            env::remove_var("EDITOR");
+        }
+        let cmd = resolve_editor_command(false).unwrap();
+        assert_eq!(cmd, vec![DEFAULT_EDITOR.to_string()]);
+    }
+
+    #[test]
+    #[serial]
+    fn resolve_editor_errors_when_unset_and_explicit_required() {
         let _guard = EnvGuard::new();
         unsafe {
             env::remove_var("VISUAL");
             env::remove_var("EDITOR");
         }
         assert!(matches!(
-            resolve_editor_command(),
+            resolve_editor_command(true),
             Err(EditorError::MissingEditor)
         ));
     }
@@ -136,7 +445,307 @@ mod tests {
         fs::set_permissions(&script_path, perms).unwrap();
 
         let cmd = vec![script_path.to_string_lossy().to_string()];
-        let result = run_editor("seed", &cmd).await.unwrap();
+        let result = run_editor("seed", &cmd, EditorOptions::default())
+            .await
+            .unwrap();
         assert_eq!(result, "edited".to_string());
     }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn run_editor_without_help_leaves_hash_lines_alone() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempdir().unwrap();
+        let script_path = dir.path().join("markdown.sh");
+        fs::write(
+            &script_path,
+            "#!/bin/sh\nprintf '# My Heading\\nbody text' > \"$1\"\n",
+        )
+        .unwrap();
+        let mut perms = fs::metadata(&script_path).unwrap().permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&script_path, perms).unwrap();
+
+        let cmd = vec![script_path.to_string_lossy().to_string()];
+        let result = run_editor("seed", &cmd, EditorOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(result, "# My Heading\nbody text".to_string());
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn run_editor_without_help_allows_empty_result() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempdir().unwrap();
+        let script_path = dir.path().join("clear_no_help.sh");
+        fs::write(&script_path, "#!/bin/sh\nprintf '' > \"$1\"\n").unwrap();
+        let mut perms = fs::metadata(&script_path).unwrap().permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&script_path, perms).unwrap();
+
+        let cmd = vec![script_path.to_string_lossy().to_string()];
+        let result = run_editor("seed", &cmd, EditorOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(result, String::new());
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn run_editor_strips_help_comment_block() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempdir().unwrap();
+        let script_path = dir.path().join("passthrough.sh");
+        fs::write(&script_path, "#!/bin/sh\nexit 0\n").unwrap();
+        let mut perms = fs::metadata(&script_path).unwrap().permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&script_path, perms).unwrap();
+
+        let cmd = vec![script_path.to_string_lossy().to_string()];
+        let options = EditorOptions {
+            help: Some(HelpBlock {
+                text: "this is help text",
+                comment_marker: '#',
+            }),
+            ..Default::default()
+        };
+        let result = run_editor("seed", &cmd, options).await.unwrap();
+        assert_eq!(result, "seed".to_string());
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn run_editor_preserves_seed_hash_lines_when_help_is_used() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempdir().unwrap();
+        let script_path = dir.path().join("passthrough2.sh");
+        fs::write(&script_path, "#!/bin/sh\nexit 0\n").unwrap();
+        let mut perms = fs::metadata(&script_path).unwrap().permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&script_path, perms).unwrap();
+
+        let cmd = vec![script_path.to_string_lossy().to_string()];
+        let options = EditorOptions {
+            help: Some(HelpBlock {
+                text: "this is help text",
+                comment_marker: '#',
+            }),
+            ..Default::default()
+        };
+        let result = run_editor("# My Heading\nbody text", &cmd, options)
+            .await
+            .unwrap();
+        assert_eq!(result, "# My Heading\nbody text".to_string());
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn run_editor_aborts_when_only_comments_remain() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempdir().unwrap();
+        let script_path = dir.path().join("clear.sh");
+        // Simulate the user deleting everything but the help block.
+        fs::write(
+            &script_path,
+            "#!/bin/sh\ngrep '^#' \"$1\" > \"$1.tmp\" && mv \"$1.tmp\" \"$1\"\n",
+        )
+        .unwrap();
+        let mut perms = fs::metadata(&script_path).unwrap().permissions();
+        perms.set_mode(0o755);
+        fs::set_permissions(&script_path, perms).unwrap();
+
+        let cmd = vec![script_path.to_string_lossy().to_string()];
+        let options = EditorOptions {
+            help: Some(HelpBlock {
+                text: "help",
+                comment_marker: '#',
+            }),
+            ..Default::default()
+        };
+        let err = run_editor("seed", &cmd, options).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<EditorError>(),
+            Some(EditorError::Aborted)
+        ));
+    }
+
+    #[test]
+    fn hardening_args_cover_known_editors() {
+        assert!(!hardening_args("vim").is_empty());
+        assert!(!hardening_args("nvim").is_empty());
+        assert!(!hardening_args("emacs").is_empty());
+        assert!(hardening_args("nano").is_empty());
+    }
+
+    #[test]
+    fn editor_basename_strips_path() {
+        let cmd = vec!["/usr/bin/vim".to_string(), "-u".to_string()];
+        assert_eq!(editor_basename(&cmd), "vim");
+    }
+
+    #[test]
+    fn cursor_position_args_use_line_prefix_for_vim() {
+        let pos = CursorPosition {
+            line: 12,
+            column: None,
+        };
+        let (args, file_arg) = cursor_position_args("vim", pos, "/tmp/buf.md");
+        assert_eq!(args, vec!["+12".to_string()]);
+        assert_eq!(file_arg, "/tmp/buf.md");
+    }
+
+    #[test]
+    fn cursor_position_args_combine_file_and_position_for_sublime() {
+        let pos = CursorPosition {
+            line: 3,
+            column: Some(7),
+        };
+        let (args, file_arg) = cursor_position_args("subl", pos, "/tmp/buf.md");
+        assert!(args.is_empty());
+        assert_eq!(file_arg, "/tmp/buf.md:3:7");
+    }
+
+    #[test]
+    fn cursor_position_args_use_goto_flag_for_code() {
+        let pos = CursorPosition {
+            line: 5,
+            column: None,
+        };
+        let (args, file_arg) = cursor_position_args("code", pos, "/tmp/buf.md");
+        assert_eq!(args, vec!["--goto".to_string()]);
+        assert_eq!(file_arg, "/tmp/buf.md:5");
+    }
+
+    #[test]
+    fn cursor_position_args_use_bare_location_for_helix() {
+        let pos = CursorPosition {
+            line: 9,
+            column: Some(2),
+        };
+        let (args, file_arg) = cursor_position_args("hx", pos, "/tmp/buf.md");
+        assert!(args.is_empty());
+        assert_eq!(file_arg, "/tmp/buf.md:9:2");
+    }
+
+    #[test]
+    fn cursor_position_args_unknown_editor_is_noop() {
+        let pos = CursorPosition {
+            line: 1,
+            column: None,
+        };
+        let (args, file_arg) = cursor_position_args("notepad.exe", pos, "/tmp/buf.md");
+        assert!(args.is_empty());
+        assert_eq!(file_arg, "/tmp/buf.md");
+    }
+
+    /// Records which [`TerminalIo`] calls were made, and can be told to fail
+    /// one of them, so [`SuspendedTerminal`] can be exercised without a real
+    /// tty.
+    #[derive(Clone, Default)]
+    struct FakeIo {
+        calls: std::sync::Arc<std::sync::Mutex<Vec<&'static str>>>,
+        fail_leave_alternate_screen: bool,
+    }
+
+    impl TerminalIo for FakeIo {
+        fn disable_raw_mode(&self) -> std::io::Result<()> {
+            self.calls.lock().unwrap().push("disable_raw_mode");
+            Ok(())
+        }
+
+        fn enable_raw_mode(&self) -> std::io::Result<()> {
+            self.calls.lock().unwrap().push("enable_raw_mode");
+            Ok(())
+        }
+
+        fn leave_alternate_screen(&self) -> std::io::Result<()> {
+            self.calls.lock().unwrap().push("leave_alternate_screen");
+            if self.fail_leave_alternate_screen {
+                return Err(std::io::Error::other("fake failure"));
+            }
+            Ok(())
+        }
+
+        fn enter_alternate_screen(&self) -> std::io::Result<()> {
+            self.calls.lock().unwrap().push("enter_alternate_screen");
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn suspended_terminal_restores_on_drop_after_successful_enter() {
+        let io = FakeIo::default();
+        {
+            let _guard = SuspendedTerminal::enter_with(io.clone()).unwrap();
+            assert_eq!(
+                *io.calls.lock().unwrap(),
+                vec!["disable_raw_mode", "leave_alternate_screen"]
+            );
+        }
+        assert_eq!(
+            *io.calls.lock().unwrap(),
+            vec![
+                "disable_raw_mode",
+                "leave_alternate_screen",
+                "enter_alternate_screen",
+                "enable_raw_mode",
+            ]
+        );
+    }
+
+    #[test]
+    fn suspended_terminal_reenables_raw_mode_if_leaving_alt_screen_fails() {
+        let io = FakeIo {
+            fail_leave_alternate_screen: true,
+            ..Default::default()
+        };
+        assert!(SuspendedTerminal::enter_with(io.clone()).is_err());
+        assert_eq!(
+            *io.calls.lock().unwrap(),
+            vec![
+                "disable_raw_mode",
+                "leave_alternate_screen",
+                "enable_raw_mode"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn run_editor_suspended_restores_terminal_even_when_editor_errors() {
+        use ratatui::backend::TestBackend;
+        use ratatui::Terminal;
+
+        let mut terminal = Terminal::new(TestBackend::new(10, 10)).unwrap();
+        let io = FakeIo::default();
+        // A nonexistent editor makes `run_editor` fail, exercising the
+        // restore-on-error path rather than the happy path.
+        let cmd = vec!["/nonexistent/does-not-exist".to_string()];
+
+        let result = run_editor_suspended_with(
+            &mut terminal,
+            "seed",
+            &cmd,
+            EditorOptions::default(),
+            io.clone(),
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            *io.calls.lock().unwrap(),
+            vec![
+                "disable_raw_mode",
+                "leave_alternate_screen",
+                "enter_alternate_screen",
+                "enable_raw_mode",
+            ]
+        );
+    }
 }